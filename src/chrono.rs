@@ -5,7 +5,7 @@ use chrono::NaiveDateTime;
 use chrono::TimeZone;
 use chrono::Utc;
 
-use crate::timestamp::Timestamp;
+use crate::Timestamp;
 
 impl Timestamp {
   /// Convert the given timestamp to a DateTime in the given time zone.
@@ -15,48 +15,43 @@ impl Timestamp {
 
   /// Convert the given timestamp into a DateTime in UTC.
   pub fn to_utc_datetime(&self) -> DateTime<Utc> {
-    DateTime::from_utc(self.to_naive_datetime(), Utc)
+    Utc.from_utc_datetime(&self.to_naive_datetime())
   }
 
   /// Convert the given timestamp into a NaiveDateTime.
   pub fn to_naive_datetime(&self) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(self.seconds, self.nanos)
+    DateTime::from_timestamp(self.seconds, self.nanos)
+      .expect("Timestamp out of range for `NaiveDateTime`.")
+      .naive_utc()
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use super::*;
+  use chrono::FixedOffset;
   use chrono::NaiveDate;
   use chrono::Timelike;
-  use chrono_tz::America::New_York;
-  use chrono_tz::Australia::Sydney;
+
+  use super::*;
 
   #[test]
   fn test_to_naive_datetime() {
     let t = Timestamp::from(1335020400);
     assert_eq!(
       t.to_naive_datetime(),
-      NaiveDate::from_ymd(2012, 4, 21).and_hms(15, 00, 0)
+      NaiveDate::from_ymd_opt(2012, 4, 21).unwrap().and_hms_opt(15, 0, 0).unwrap()
     );
   }
 
   #[test]
   fn test_to_datetime() {
     let t = Timestamp::from(1335020400);
+    let offset = FixedOffset::east_opt(-4 * 3600).unwrap();
     assert_eq!(
-      t.to_datetime(&New_York),
-      New_York
-        .from_local_datetime(
-          &NaiveDate::from_ymd(2012, 4, 21).and_hms(11, 0, 0)
-        )
-        .unwrap(),
-    );
-    assert_eq!(
-      t.to_datetime(&Sydney),
-      Sydney
+      t.to_datetime(&offset),
+      offset
         .from_local_datetime(
-          &NaiveDate::from_ymd(2012, 4, 22).and_hms(1, 0, 0)
+          &NaiveDate::from_ymd_opt(2012, 4, 21).unwrap().and_hms_opt(11, 0, 0).unwrap()
         )
         .unwrap(),
     );
@@ -69,7 +64,7 @@ mod tests {
       t.to_utc_datetime(),
       Utc
         .from_local_datetime(
-          &NaiveDate::from_ymd(2012, 4, 21).and_hms(15, 0, 0),
+          &NaiveDate::from_ymd_opt(2012, 4, 21).unwrap().and_hms_opt(15, 0, 0).unwrap(),
         )
         .unwrap(),
     );
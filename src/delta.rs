@@ -0,0 +1,107 @@
+use core::ops::Sub;
+
+use crate::Timestamp;
+
+/// The signed difference between two `Timestamp`s, as returned by `Timestamp`'s `Sub` impl.
+///
+/// Unlike `Timestamp`, a `Delta` can be negative: it represents an elapsed (or yet-to-elapse)
+/// interval rather than an instant, so subtracting a later timestamp from an earlier one produces
+/// a negative `Delta` rather than wrapping or panicking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Delta {
+  seconds: i64,
+  nanos: i32,
+}
+
+impl Delta {
+  /// Return the whole-seconds component of this delta, truncated toward zero.
+  pub const fn whole_seconds(&self) -> i64 {
+    self.seconds
+  }
+
+  /// Return the sub-second nanosecond component of this delta.
+  ///
+  /// This always has the same sign as the delta as a whole (or is zero), so a delta of `-1.25s`
+  /// has `whole_seconds() == -1` and `subsec_nanos() == -250_000_000`, and a delta of `-0.25s`
+  /// has `whole_seconds() == 0` and `subsec_nanos() == -250_000_000`.
+  pub const fn subsec_nanos(&self) -> i32 {
+    self.nanos
+  }
+
+  /// Return this delta as a floating-point number of seconds.
+  pub fn as_seconds_f64(&self) -> f64 {
+    self.seconds as f64 + self.nanos as f64 / 1_000_000_000.0
+  }
+}
+
+impl Sub for Timestamp {
+  type Output = Delta;
+
+  /// Subtract the provided timestamp from this one, returning the signed interval between them.
+  ///
+  /// Saturates to `i64::MIN`/`i64::MAX` rather than panicking or wrapping when the two
+  /// timestamps are far enough apart that the difference itself can't fit in an `i64` (e.g.
+  /// `Timestamp::new(i64::MAX, 0) - Timestamp::new(i64::MIN, 0)`).
+  fn sub(self, other: Timestamp) -> Delta {
+    let mut seconds = self.seconds.saturating_sub(other.seconds);
+    let mut nanos = self.nanos as i64 - other.nanos as i64;
+    if nanos < 0 {
+      seconds = seconds.saturating_sub(1);
+      nanos += 1_000_000_000;
+    }
+    // `seconds`/`nanos` is now a non-negative fractional part with the sign folded into
+    // `seconds`; fold it back out so `nanos` always matches the sign of `seconds`.
+    if seconds < 0 && nanos != 0 {
+      Delta { seconds: seconds.saturating_add(1), nanos: (nanos - 1_000_000_000) as i32 }
+    }
+    else {
+      Delta { seconds, nanos: nanos as i32 }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_positive_delta() {
+    let delta = Timestamp::new(5, 0) - Timestamp::new(3, 500_000_000);
+    assert_eq!(delta.whole_seconds(), 1);
+    assert_eq!(delta.subsec_nanos(), 500_000_000);
+    assert_eq!(delta.as_seconds_f64(), 1.5);
+  }
+
+  #[test]
+  fn test_negative_delta_whole_seconds() {
+    let delta = Timestamp::new(3, 0) - Timestamp::new(5, 0);
+    assert_eq!(delta.whole_seconds(), -2);
+    assert_eq!(delta.subsec_nanos(), 0);
+  }
+
+  #[test]
+  fn test_negative_delta_with_nanos() {
+    let delta = Timestamp::new(3, 250_000_000) - Timestamp::new(5, 500_000_000);
+    assert_eq!(delta.whole_seconds(), -2);
+    assert_eq!(delta.subsec_nanos(), -250_000_000);
+    assert_eq!(delta.as_seconds_f64(), -2.25);
+  }
+
+  #[test]
+  fn test_zero_delta() {
+    let delta = Timestamp::new(5, 0) - Timestamp::new(5, 0);
+    assert_eq!(delta.whole_seconds(), 0);
+    assert_eq!(delta.subsec_nanos(), 0);
+  }
+
+  #[test]
+  fn test_delta_saturates_instead_of_overflowing() {
+    let delta = Timestamp::new(i64::MAX, 0) - Timestamp::new(i64::MIN, 0);
+    assert_eq!(delta.whole_seconds(), i64::MAX);
+    assert_eq!(delta.subsec_nanos(), 0);
+
+    let delta = Timestamp::new(i64::MIN, 0) - Timestamp::new(i64::MAX, 0);
+    assert_eq!(delta.whole_seconds(), i64::MIN);
+    assert_eq!(delta.subsec_nanos(), 0);
+  }
+}
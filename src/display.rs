@@ -1,9 +1,12 @@
-use std::fmt::Display;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::str::FromStr;
 
 use crate::Timestamp;
 
 impl Display for Timestamp {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     match f.precision() {
       Some(p) => {
         let float = self.seconds as f64 + self.nanos as f64 / 1_000_000_000.0;
@@ -14,14 +17,180 @@ impl Display for Timestamp {
   }
 }
 
+/// An error returned when parsing a `Timestamp` from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseTimestampError {
+  reason: &'static str,
+}
+
+impl Display for ParseTimestampError {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "invalid timestamp: {}", self.reason)
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTimestampError {}
+
+impl FromStr for Timestamp {
+  type Err = ParseTimestampError;
+
+  /// Parse a `Timestamp` from the same grammar the `ts!` macro accepts: an optional leading `-`,
+  /// an optional integer part, and an optional `.fraction` of up to nine digits, including
+  /// leading-`.` forms like `.5` and `-.5`.
+  ///
+  /// Negative values use the same rounding as the `ts!` macro: a negative value with a non-zero
+  /// fraction decrements `seconds` by one and keeps the fraction digits as `nanos` unchanged
+  /// (e.g. `-0.25` becomes `Timestamp::new(-1, 250_000_000)`).
+  ///
+  /// Non-negative output of the precision-aware `Display` impl (e.g. `format!("{:.9}", ts)`)
+  /// parses back to the same timestamp when `f64` holds all of its digits. `ts.to_string()`
+  /// without a precision writes whole seconds only, so parsing it drops the sub-second part.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Err(ParseTimestampError { reason: "empty input" });
+    }
+
+    let neg = s.starts_with('-');
+    let s = s.strip_prefix('-').unwrap_or(s);
+    if s.is_empty() {
+      return Err(ParseTimestampError { reason: "missing digits" });
+    }
+
+    let mut parts = s.splitn(2, '.');
+    let whole = parts.next().unwrap();
+    let whole = if whole.is_empty() { "0" } else { whole };
+    let magnitude = whole
+      .parse::<u64>()
+      .map_err(|_| ParseTimestampError { reason: "invalid integer part" })?;
+    // Negate the magnitude directly instead of parsing into a positive `i64` and negating
+    // afterward, so `i64::MIN` (whose magnitude overflows `i64`) still parses.
+    let mut seconds = if neg {
+      if magnitude == i64::MIN.unsigned_abs() {
+        i64::MIN
+      }
+      else {
+        let positive: i64 = magnitude
+          .try_into()
+          .map_err(|_| ParseTimestampError { reason: "integer part out of range" })?;
+        -positive
+      }
+    }
+    else {
+      i64::try_from(magnitude)
+        .map_err(|_| ParseTimestampError { reason: "integer part out of range" })?
+    };
+
+    let nanos = match parts.next() {
+      Some(frac) => {
+        let digits = frac.len() as u32;
+        if digits == 0 || digits > 9 {
+          return Err(ParseTimestampError { reason: "invalid fractional part" });
+        }
+        let value = frac
+          .parse::<u32>()
+          .map_err(|_| ParseTimestampError { reason: "invalid fractional part" })?;
+        value * 10u32.pow(9 - digits)
+      },
+      None => 0,
+    };
+
+    if neg && nanos != 0 {
+      seconds = seconds
+        .checked_sub(1)
+        .ok_or(ParseTimestampError { reason: "integer part out of range" })?;
+    }
+    Ok(Timestamp::new(seconds, nanos))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  // `format!` needs `alloc`, which this crate doesn't otherwise depend on; gate behind `std`
+  // rather than pulling in `extern crate alloc` just for a handful of assertions.
+  #[cfg(feature = "std")]
   #[test]
   fn test_display() {
     let t = Timestamp::from(1335020400);
     assert_eq!(format!("{:.02}", t), "1335020400.00");
     assert_eq!(format!("{}", t), "1335020400");
   }
+
+  #[test]
+  fn test_from_str_integer() {
+    assert_eq!("1335020400".parse::<Timestamp>().unwrap(), Timestamp::new(1335020400, 0));
+  }
+
+  #[test]
+  fn test_from_str_decimal() {
+    assert_eq!(
+      "1335020400.50".parse::<Timestamp>().unwrap(),
+      Timestamp::new(1335020400, 500_000_000)
+    );
+  }
+
+  #[test]
+  fn test_from_str_negative() {
+    assert_eq!("-1000".parse::<Timestamp>().unwrap(), Timestamp::new(-1000, 0));
+  }
+
+  #[test]
+  fn test_from_str_negative_with_nanos() {
+    let t = "-10000.25".parse::<Timestamp>().unwrap();
+    assert_eq!(t.seconds(), -10001);
+    assert_eq!(t.subsec(2), 25);
+  }
+
+  #[test]
+  fn test_from_str_leading_dot() {
+    let t = ".5".parse::<Timestamp>().unwrap();
+    assert_eq!(t.seconds(), 0);
+    assert_eq!(t.subsec(1), 5);
+  }
+
+  #[test]
+  fn test_from_str_negative_leading_dot() {
+    let t = "-.5".parse::<Timestamp>().unwrap();
+    assert_eq!(t.seconds(), -1);
+    assert_eq!(t.subsec(1), 5);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_from_str_round_trip() {
+    let t = Timestamp::new(1335020400, 500_000_000);
+    assert_eq!(format!("{:.9}", t).parse::<Timestamp>().unwrap(), t);
+  }
+
+  #[test]
+  fn test_from_str_invalid() {
+    assert!("not a number".parse::<Timestamp>().is_err());
+    assert!("".parse::<Timestamp>().is_err());
+    assert!("-".parse::<Timestamp>().is_err());
+  }
+
+  #[test]
+  fn test_from_str_negative_fraction_overflow() {
+    // A negative input whose magnitude is exactly `i64::MIN` and has a fractional part would
+    // need to decrement past `i64::MIN`; this must be a `ParseTimestampError`, not a panic.
+    assert!("-9223372036854775808.5".parse::<Timestamp>().is_err());
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_from_str_i64_min() {
+    // `i64::MIN`'s magnitude overflows `i64`, so this exercises the unsigned-magnitude path.
+    let t = Timestamp::new(i64::MIN, 0);
+    assert_eq!(t.to_string().parse::<Timestamp>().unwrap(), t);
+  }
+
+  #[test]
+  fn test_from_str_i64_min_with_fraction() {
+    let t = "-9223372036854775807.5".parse::<Timestamp>().unwrap();
+    assert_eq!(t.seconds(), i64::MIN);
+    assert_eq!(t.subsec(1), 5);
+  }
 }
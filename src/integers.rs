@@ -1,8 +1,8 @@
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Rem;
-use std::ops::Sub;
-use std::ops::SubAssign;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Rem;
+use core::ops::Sub;
+use core::ops::SubAssign;
 
 use crate::Timestamp;
 
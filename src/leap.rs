@@ -0,0 +1,147 @@
+//! Leap-second aware conversion between Unix time and TAI (International Atomic Time).
+//!
+//! Unix timestamps are UTC-based and so are discontinuous across leap seconds, while TAI is a
+//! continuous time scale that runs a fixed, ever-growing number of seconds ahead of UTC. This
+//! module embeds the IERS leap-second history so a `Timestamp` can be converted to and from a
+//! monotonic TAI view.
+
+use crate::Timestamp;
+
+/// A `(unix_seconds, tai_minus_utc)` entry in a leap-second table.
+///
+/// `unix_seconds` is the UTC instant (as a Unix timestamp) at which `tai_minus_utc` takes effect.
+/// A table must be sorted ascending by `unix_seconds` for lookups to be valid.
+pub type LeapEntry = (i64, i64);
+
+/// The built-in leap-second table, from the IERS leap-second history.
+///
+/// Timestamps before the first entry (prior to 1972) are treated as having a zero offset.
+pub const LEAP_SECONDS: &[LeapEntry] = &[
+  (63072000, 10),
+  (78796800, 11),
+  (94694400, 12),
+  (126230400, 13),
+  (157766400, 14),
+  (189302400, 15),
+  (220924800, 16),
+  (252460800, 17),
+  (283996800, 18),
+  (315532800, 19),
+  (362793600, 20),
+  (394329600, 21),
+  (425865600, 22),
+  (489024000, 23),
+  (567993600, 24),
+  (631152000, 25),
+  (662688000, 26),
+  (709948800, 27),
+  (741484800, 28),
+  (773020800, 29),
+  (820454400, 30),
+  (867715200, 31),
+  (915148800, 32),
+  (1136073600, 33),
+  (1230768000, 34),
+  (1341100800, 35),
+  (1435708800, 36),
+  (1483228800, 37),
+];
+
+impl Timestamp {
+  /// Return the cumulative TAI-UTC offset, in seconds, in effect at this timestamp, per the
+  /// built-in `LEAP_SECONDS` table.
+  pub fn leap_offset(&self) -> i64 {
+    leap_offset_in(self.seconds, LEAP_SECONDS)
+  }
+
+  /// Convert this Unix (UTC) timestamp to TAI, per the built-in `LEAP_SECONDS` table.
+  pub fn to_tai(&self) -> Timestamp {
+    self.to_tai_with_table(LEAP_SECONDS)
+  }
+
+  /// Convert a TAI timestamp back to Unix (UTC), per the built-in `LEAP_SECONDS` table.
+  pub fn from_tai(tai: Timestamp) -> Timestamp {
+    Timestamp::from_tai_with_table(tai, LEAP_SECONDS)
+  }
+
+  /// Like `to_tai`, but binary-searches the given table instead of the built-in one, for
+  /// environments that update leap seconds out of band.
+  pub fn to_tai_with_table(&self, table: &[LeapEntry]) -> Timestamp {
+    let offset = leap_offset_in(self.seconds, table);
+    Timestamp::new(self.seconds + offset, self.nanos)
+  }
+
+  /// Like `from_tai`, but binary-searches the given table instead of the built-in one.
+  pub fn from_tai_with_table(tai: Timestamp, table: &[LeapEntry]) -> Timestamp {
+    let offset = leap_offset_in_tai(tai.seconds, table);
+    Timestamp::new(tai.seconds - offset, tai.nanos)
+  }
+}
+
+/// Binary-search `table` (sorted ascending by `unix_seconds`) for the offset in effect at
+/// `seconds`, treating anything before the first entry as offset `0`.
+fn leap_offset_in(seconds: i64, table: &[LeapEntry]) -> i64 {
+  match table.binary_search_by_key(&seconds, |entry| entry.0) {
+    Ok(i) => table[i].1,
+    Err(0) => 0,
+    Err(i) => table[i - 1].1,
+  }
+}
+
+/// Like `leap_offset_in`, but for a TAI value instead of a UTC one: `table` is keyed by
+/// `unix_seconds`, so each entry's own transition point in TAI is `unix_seconds + tai_minus_utc`,
+/// not `unix_seconds` itself.
+fn leap_offset_in_tai(tai_seconds: i64, table: &[LeapEntry]) -> i64 {
+  match table.binary_search_by_key(&tai_seconds, |entry| entry.0 + entry.1) {
+    Ok(i) => table[i].1,
+    Err(0) => 0,
+    Err(i) => table[i - 1].1,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_leap_offset_before_table() {
+    assert_eq!(Timestamp::from(0).leap_offset(), 0);
+  }
+
+  #[test]
+  fn test_leap_offset_exact_entry() {
+    assert_eq!(Timestamp::from(1483228800).leap_offset(), 37);
+  }
+
+  #[test]
+  fn test_leap_offset_between_entries() {
+    assert_eq!(Timestamp::from(1335020400).leap_offset(), 34);
+  }
+
+  #[test]
+  fn test_to_tai_and_back() {
+    let ts = Timestamp::from(1335020400);
+    let tai = ts.to_tai();
+    assert_eq!(tai.seconds(), 1335020400 + 34);
+    assert_eq!(Timestamp::from_tai(tai), ts);
+  }
+
+  #[test]
+  fn test_to_tai_and_back_across_leap_boundary() {
+    // The last second before the 1483228800 (2017-01-01) leap second still uses the prior
+    // offset of 36, even though its TAI value (1483228799 + 36 = 1483228835) is numerically
+    // past where the UTC-keyed table would place the next entry.
+    let ts = Timestamp::from(1483228799);
+    let tai = ts.to_tai();
+    assert_eq!(tai.seconds(), 1483228799 + 36);
+    assert_eq!(Timestamp::from_tai(tai), ts);
+  }
+
+  #[test]
+  fn test_custom_table() {
+    let table: &[LeapEntry] = &[(100, 1), (200, 2)];
+    assert_eq!(Timestamp::from(50).to_tai_with_table(table).seconds(), 50);
+    assert_eq!(Timestamp::from(150).to_tai_with_table(table).seconds(), 151);
+    assert_eq!(Timestamp::from(250).to_tai_with_table(table).seconds(), 252);
+  }
+}
@@ -3,15 +3,131 @@
 //! Unix timestamps are one of the most common ways to exchange time data. A Unix timestamp is
 //! simply the number of seconds (and, optionally, fractions of a second) that have elapsed since
 //! January 1, 1970 at midnight UTC.
+//!
+//! This crate is `no_std` when the default-on `std` feature is disabled; that feature provides
+//! `Timestamp::now()` and the CUC wire encoding in the `wire` module, both of which need the
+//! standard library.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+mod delta;
 mod display;
 mod integers;
+mod leap;
 mod std_duration;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "std")]
+mod wire;
+
+pub use delta::Delta;
+pub use display::ParseTimestampError;
+pub use leap::LeapEntry;
+pub use leap::LEAP_SECONDS;
+#[cfg(feature = "std")]
+pub use wire::ParseError as WireParseError;
 
 pub use unix_ts_macros::ts;
 
+/// Serde support for `Timestamp`, as the `{ "seconds": ..., "nanos": ... }` struct shown below.
+///
+/// Enabled by the `serde` feature. For a single fractional-second number or string instead (e.g.
+/// `1335020400.5`), use `#[serde(with = "unix_ts::fractional")]` on the field instead.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timestamp {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeStruct;
+    let mut state = serializer.serialize_struct("Timestamp", 2)?;
+    state.serialize_field("seconds", &self.seconds)?;
+    state.serialize_field("nanos", &self.nanos)?;
+    state.end()
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timestamp {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    #[derive(serde::Deserialize)]
+    struct Raw {
+      seconds: i64,
+      nanos: u32,
+    }
+    let raw = Raw::deserialize(deserializer)?;
+    Ok(Timestamp::new(raw.seconds, raw.nanos))
+  }
+}
+
+/// An alternate serde representation of `Timestamp` as a single fractional-second number (or,
+/// when deserializing, a string in the same form), compatible with the `Display` impl and the
+/// `ts!` macro grammar (e.g. `1335020400.5`).
+///
+/// Use via `#[serde(with = "unix_ts::fractional")]` on a `Timestamp` field.
+#[cfg(feature = "serde")]
+pub mod fractional {
+  use serde::Deserializer;
+  use serde::Serializer;
+
+  use crate::Timestamp;
+
+  /// Serialize a `Timestamp` as a single fractional-second `f64`.
+  pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(ts.seconds as f64 + ts.nanos as f64 / 1_000_000_000.0)
+  }
+
+  /// Deserialize a `Timestamp` from a fractional-second number or an equivalent string.
+  ///
+  /// The string form is parsed with `Timestamp`'s own `FromStr` rather than through `f64`, so it
+  /// doesn't lose precision for timestamps with more significant digits than `f64` can hold.
+  ///
+  /// Reuses `Timestamp::new`'s nanos-normalization, so the "nanos is always a positive offset"
+  /// invariant holds for negative seconds (e.g. `-0.25` becomes `Timestamp::new(-1,
+  /// 750_000_000)`).
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+    use core::str::FromStr;
+
+    struct FractionalVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for FractionalVisitor {
+      type Value = Timestamp;
+
+      fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a fractional-second number or string")
+      }
+
+      fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        let seconds = v.floor() as i64;
+        let nanos = ((v - v.floor()) * 1_000_000_000.0).round() as u32;
+        Ok(Timestamp::new(seconds, nanos))
+      }
+
+      fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Timestamp::new(v, 0))
+      }
+
+      fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Timestamp::new(v as i64, 0))
+      }
+
+      fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let ts = Timestamp::from_str(v).map_err(|_| E::custom("invalid fractional-second string"))?;
+        // `FromStr` keeps a negative value's fraction digits as `nanos`; take their complement
+        // so `nanos` is a positive offset, as for the number form.
+        if v.trim().starts_with('-') && ts.nanos != 0 {
+          return Ok(Timestamp::new(ts.seconds, 1_000_000_000 - ts.nanos));
+        }
+        Ok(ts)
+      }
+    }
+
+    deserializer.deserialize_any(FractionalVisitor)
+  }
+}
+
 /// A representation of a timestamp (seconds and nanos since the Unix epoch).
 ///
 /// Timestamps are able to be easily converted into chrono DateTimes.
@@ -71,6 +187,7 @@ impl Timestamp {
   /// ## Panic
   ///
   /// Panics if the system clock is set to a time prior to the Unix epoch (January 1, 1970).
+  #[cfg(feature = "std")]
   pub fn now() -> Self {
     let now_dur = SystemTime::now()
       .duration_since(SystemTime::UNIX_EPOCH)
@@ -137,10 +254,56 @@ impl Timestamp {
   }
 }
 
+/// Checked and saturating arithmetic.
+impl Timestamp {
+  /// Create a new timestamp from the given `seconds` and `nanos`, returning `None` instead of
+  /// panicking if normalizing `nanos` into `seconds` overflows `i64`.
+  pub const fn new_checked(mut seconds: i64, mut nanos: u32) -> Option<Self> {
+    while nanos >= 1_000_000_000 {
+      seconds = match seconds.checked_add(1) {
+        Some(seconds) => seconds,
+        None => return None,
+      };
+      nanos -= 1_000_000_000;
+    }
+    Some(Timestamp { seconds, nanos })
+  }
+
+  /// Add the given number of seconds to this timestamp, returning `None` on `i64` overflow
+  /// instead of panicking or wrapping.
+  pub const fn checked_add(&self, seconds: i64) -> Option<Self> {
+    match self.seconds.checked_add(seconds) {
+      Some(seconds) => Some(Timestamp { seconds, nanos: self.nanos }),
+      None => None,
+    }
+  }
+
+  /// Subtract the given number of seconds from this timestamp, returning `None` on `i64`
+  /// overflow instead of panicking or wrapping.
+  pub const fn checked_sub(&self, seconds: i64) -> Option<Self> {
+    match self.seconds.checked_sub(seconds) {
+      Some(seconds) => Some(Timestamp { seconds, nanos: self.nanos }),
+      None => None,
+    }
+  }
+
+  /// Add the given number of seconds to this timestamp, clamping to `i64::MIN`/`i64::MAX`
+  /// instead of overflowing.
+  pub const fn saturating_add(&self, seconds: i64) -> Self {
+    Timestamp { seconds: self.seconds.saturating_add(seconds), nanos: self.nanos }
+  }
+
+  /// Subtract the given number of seconds from this timestamp, clamping to `i64::MIN`/`i64::MAX`
+  /// instead of overflowing.
+  pub const fn saturating_sub(&self, seconds: i64) -> Self {
+    Timestamp { seconds: self.seconds.saturating_sub(seconds), nanos: self.nanos }
+  }
+}
+
 #[cfg(test)]
 #[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
-  use std::time::Duration;
+  use core::time::Duration;
 
   use assert2::check;
 
@@ -220,4 +383,82 @@ mod tests {
     assert_eq!(ts.seconds(), 1335020399);
     assert_eq!(ts.subsec(1), 5);
   }
+
+  #[test]
+  fn test_new_checked() {
+    let expected = Some(Timestamp::new(1335020400, 500_000_000));
+    check!(Timestamp::new_checked(1335020400, 500_000_000) == expected);
+    check!(Timestamp::new_checked(i64::MAX, 1_000_000_000) == None);
+  }
+
+  #[test]
+  fn test_checked_add() {
+    let expected = Some(Timestamp::from(1335020400 + 86400));
+    check!(Timestamp::from(1335020400).checked_add(86400) == expected);
+    check!(Timestamp::from(i64::MAX).checked_add(1) == None);
+  }
+
+  #[test]
+  fn test_checked_sub() {
+    let expected = Some(Timestamp::from(1335020400 - 86400));
+    check!(Timestamp::from(1335020400).checked_sub(86400) == expected);
+    check!(Timestamp::from(i64::MIN).checked_sub(1) == None);
+  }
+
+  #[test]
+  fn test_saturating_add() {
+    check!(Timestamp::from(i64::MAX).saturating_add(1) == Timestamp::from(i64::MAX));
+  }
+
+  #[test]
+  fn test_saturating_sub() {
+    check!(Timestamp::from(i64::MIN).saturating_sub(1) == Timestamp::from(i64::MIN));
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_serde_struct_round_trip() {
+    let t = Timestamp::new(1335020400, 500_000_000);
+    let json = serde_json::to_string(&t).unwrap();
+    check!(json == r#"{"seconds":1335020400,"nanos":500000000}"#);
+    check!(serde_json::from_str::<Timestamp>(&json).unwrap() == t);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_fractional_round_trip() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "crate::fractional")] Timestamp);
+
+    let t = Timestamp::new(1335020400, 500_000_000);
+    let json = serde_json::to_string(&Wrapper(t)).unwrap();
+    check!(json == "1335020400.5");
+    check!(serde_json::from_str::<Wrapper>(&json).unwrap().0 == t);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_fractional_negative_seconds() {
+    // -0.25 seconds is `Timestamp::new(-1, 750_000_000)`, per `fractional::deserialize`'s doc
+    // comment.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "crate::fractional")] Timestamp);
+
+    let t = Timestamp::new(-1, 750_000_000);
+    let json = serde_json::to_string(&Wrapper(t)).unwrap();
+    check!(json == "-0.25");
+    check!(serde_json::from_str::<Wrapper>(&json).unwrap().0 == t);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_fractional_deserialize_string_full_precision() {
+    // The string form exists precisely so callers aren't limited to f64's ~15-17 significant
+    // digits; deserializing it must not round-trip through a lossy f64 conversion.
+    #[derive(serde::Deserialize)]
+    struct Wrapper(#[serde(with = "crate::fractional")] Timestamp);
+
+    let Wrapper(t) = serde_json::from_str(r#""1335020400.123456789""#).unwrap();
+    check!(t == Timestamp::new(1335020400, 123456789));
+  }
 }
@@ -1,24 +1,25 @@
-use std::convert::TryInto;
-use std::ops::Add;
-use std::ops::AddAssign;
-use std::ops::Sub;
-use std::ops::SubAssign;
+use core::convert::TryInto;
+use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::Sub;
+use core::ops::SubAssign;
+use core::time::Duration;
 
 use crate::Timestamp;
 
-impl Add<std::time::Duration> for Timestamp {
+impl Add<Duration> for Timestamp {
   type Output = Self;
 
   /// Add the provided duration to the timestamp.
-  fn add(self, other: std::time::Duration) -> Timestamp {
+  fn add(self, other: Duration) -> Timestamp {
     let s: i64 = other.as_secs() as i64;
     Timestamp::new(self.seconds + s, self.nanos + other.subsec_nanos())
   }
 }
 
-impl AddAssign<std::time::Duration> for Timestamp {
+impl AddAssign<Duration> for Timestamp {
   /// Add the provided duration to the timestamp, in-place.
-  fn add_assign(&mut self, other: std::time::Duration) {
+  fn add_assign(&mut self, other: Duration) {
     let delta: i64 = other.as_secs() as i64;
     self.seconds += delta;
     self.nanos += other.subsec_nanos();
@@ -29,11 +30,11 @@ impl AddAssign<std::time::Duration> for Timestamp {
   }
 }
 
-impl Sub<std::time::Duration> for Timestamp {
+impl Sub<Duration> for Timestamp {
   type Output = Self;
 
   /// Subtract the provided duration from the timestamp.
-  fn sub(self, other: std::time::Duration) -> Timestamp {
+  fn sub(self, other: Duration) -> Timestamp {
     let other_sec: i64 = other.as_secs().try_into().unwrap();
     if other.subsec_nanos() > self.nanos {
       return Timestamp::new(
@@ -45,9 +46,9 @@ impl Sub<std::time::Duration> for Timestamp {
   }
 }
 
-impl SubAssign<std::time::Duration> for Timestamp {
+impl SubAssign<Duration> for Timestamp {
   /// Subtract the provided duration to the timestamp, in-place.
-  fn sub_assign(&mut self, other: std::time::Duration) {
+  fn sub_assign(&mut self, other: Duration) {
     let delta: i64 = other.as_secs().try_into().unwrap();
     self.seconds -= delta;
     if other.subsec_nanos() > self.nanos {
@@ -58,28 +59,56 @@ impl SubAssign<std::time::Duration> for Timestamp {
   }
 }
 
+impl Timestamp {
+  /// Add the given duration to this timestamp, returning `None` on overflow instead of
+  /// panicking or wrapping.
+  pub fn checked_add_duration(&self, other: Duration) -> Option<Timestamp> {
+    let secs: i64 = other.as_secs().try_into().ok()?;
+    let seconds = self.seconds.checked_add(secs)?;
+    Timestamp::new_checked(seconds, self.nanos + other.subsec_nanos())
+  }
+
+  /// Subtract the given duration from this timestamp, returning `None` on overflow instead of
+  /// panicking or wrapping.
+  pub fn checked_sub_duration(&self, other: Duration) -> Option<Timestamp> {
+    let secs: i64 = other.as_secs().try_into().ok()?;
+    let seconds = self.seconds.checked_sub(secs)?;
+    if other.subsec_nanos() > self.nanos {
+      Some(Timestamp::new(
+        seconds.checked_sub(1)?,
+        self.nanos + 1_000_000_000 - other.subsec_nanos(),
+      ))
+    }
+    else {
+      Some(Timestamp::new(seconds, self.nanos - other.subsec_nanos()))
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
+  use core::time::Duration;
+
   use super::*;
 
   #[test]
   fn test_add() {
     let ts = Timestamp::new(1335020400, 0);
-    let dur = std::time::Duration::new(86400, 0);
+    let dur = Duration::new(86400, 0);
     assert_eq!(ts + dur, Timestamp::new(1335020400 + 86400, 0));
   }
 
   #[test]
   fn test_add_assign() {
     let mut ts = Timestamp::new(1335020400, 0);
-    ts += std::time::Duration::new(86400, 0);
+    ts += Duration::new(86400, 0);
     assert_eq!(ts, Timestamp::new(1335020400 + 86400, 0));
   }
 
   #[test]
   fn test_add_assign_nano_overflow() {
     let mut ts = Timestamp::new(1335020400, 500_000_000);
-    ts += std::time::Duration::new(0, 750_000_000);
+    ts += Duration::new(0, 750_000_000);
     assert_eq!(ts.seconds, 1335020401);
     assert_eq!(ts.nanos, 250_000_000);
   }
@@ -87,13 +116,13 @@ mod tests {
   #[test]
   fn test_sub() {
     let ts = Timestamp::new(1335020400, 0);
-    let dur = std::time::Duration::new(86400, 0);
+    let dur = Duration::new(86400, 0);
     assert_eq!(ts - dur, Timestamp::new(1335020400 - 86400, 0));
   }
 
   #[test]
   fn test_sub_nano_overflow() {
-    let ts = Timestamp::new(1335020400, 500_000_000) - std::time::Duration::new(0, 750_000_000);
+    let ts = Timestamp::new(1335020400, 500_000_000) - Duration::new(0, 750_000_000);
     assert_eq!(ts.seconds, 1335020399);
     assert_eq!(ts.nanos, 750_000_000);
   }
@@ -101,7 +130,7 @@ mod tests {
   #[test]
   fn test_sub_assign() {
     let mut ts = Timestamp::new(1335020400, 0);
-    ts -= std::time::Duration::new(86400, 0);
+    ts -= Duration::new(86400, 0);
     assert_eq!(ts.seconds, 1335020400 - 86400);
     assert_eq!(ts.nanos, 0);
   }
@@ -109,8 +138,28 @@ mod tests {
   #[test]
   fn test_sub_assign_nano_overflow() {
     let mut ts = Timestamp::new(1335020400, 500_000_000);
-    ts -= std::time::Duration::new(0, 750_000_000);
+    ts -= Duration::new(0, 750_000_000);
     assert_eq!(ts.seconds, 1335020399);
     assert_eq!(ts.nanos, 750_000_000);
   }
+
+  #[test]
+  fn test_checked_add_duration() {
+    let ts = Timestamp::new(1335020400, 0);
+    assert_eq!(
+      ts.checked_add_duration(Duration::new(86400, 0)),
+      Some(Timestamp::new(1335020400 + 86400, 0)),
+    );
+    assert_eq!(Timestamp::new(i64::MAX, 0).checked_add_duration(Duration::new(1, 0)), None);
+  }
+
+  #[test]
+  fn test_checked_sub_duration() {
+    let ts = Timestamp::new(1335020400, 0);
+    assert_eq!(
+      ts.checked_sub_duration(Duration::new(86400, 0)),
+      Some(Timestamp::new(1335020400 - 86400, 0)),
+    );
+    assert_eq!(Timestamp::new(i64::MIN, 0).checked_sub_duration(Duration::new(1, 0)), None);
+  }
 }
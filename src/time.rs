@@ -0,0 +1,60 @@
+extern crate time;
+
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+
+use crate::Timestamp;
+
+impl Timestamp {
+  /// Convert the given timestamp into an `OffsetDateTime`, in UTC.
+  pub fn to_offset_datetime(&self) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(self.seconds)
+      .expect("Timestamp out of range for `OffsetDateTime`.")
+      .replace_nanosecond(self.nanos)
+      .expect("Timestamp has an invalid nanosecond component.")
+  }
+
+  /// Convert the given timestamp into a `PrimitiveDateTime`, in UTC.
+  pub fn to_primitive_datetime(&self) -> PrimitiveDateTime {
+    let dt = self.to_offset_datetime();
+    PrimitiveDateTime::new(dt.date(), dt.time())
+  }
+}
+
+impl From<OffsetDateTime> for Timestamp {
+  /// Create a timestamp from the given `OffsetDateTime`, preserving sub-second precision.
+  fn from(dt: OffsetDateTime) -> Timestamp {
+    Timestamp::new(dt.unix_timestamp(), dt.nanosecond())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use time::macros::datetime;
+
+  use super::*;
+
+  #[test]
+  fn test_to_offset_datetime() {
+    let t = Timestamp::from(1335020400);
+    assert_eq!(t.to_offset_datetime(), datetime!(2012-04-21 15:00:00 UTC));
+  }
+
+  #[test]
+  fn test_to_offset_datetime_nanos() {
+    let t = Timestamp::new(1335020400, 500_000_000);
+    assert_eq!(t.to_offset_datetime(), datetime!(2012-04-21 15:00:00.5 UTC));
+  }
+
+  #[test]
+  fn test_to_primitive_datetime() {
+    let t = Timestamp::from(1335020400);
+    assert_eq!(t.to_primitive_datetime(), datetime!(2012-04-21 15:00:00));
+  }
+
+  #[test]
+  fn test_from_offset_datetime() {
+    let dt = datetime!(2012-04-21 15:00:00.5 UTC);
+    assert_eq!(Timestamp::from(dt), Timestamp::new(1335020400, 500_000_000));
+  }
+}
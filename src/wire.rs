@@ -0,0 +1,189 @@
+//! Binary wire encoding and decoding for `Timestamp`, in the style of the CCSDS Unsegmented Time
+//! Code (CUC).
+//!
+//! The encoding begins with a one-byte P-field recording how many coarse-time octets (seconds)
+//! and fine-time octets (sub-seconds) follow, then the coarse seconds big-endian, then the
+//! sub-second fraction big-endian.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::Timestamp;
+
+/// An error encountered while decoding a `Timestamp` from CUC-encoded bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+  /// The buffer was too short to even contain the one-byte P-field.
+  MissingPField,
+
+  /// The buffer did not contain as many coarse/fine octets as the P-field declared.
+  BufferTooShort {
+    /// The number of octets the P-field says should follow the P-field itself.
+    expected: usize,
+    /// The number of octets actually available after the P-field.
+    actual: usize,
+  },
+
+  /// The P-field had bits set outside the ones this crate's encoder ever produces (bits 0-3),
+  /// which would otherwise decode to a `coarse`/`fine` combination `to_cuc_bytes` can't create.
+  ReservedBitsSet {
+    /// The P-field byte as read from the buffer.
+    p_field: u8,
+  },
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseError::MissingPField => write!(f, "buffer is too short to contain a P-field"),
+      ParseError::BufferTooShort { expected, actual } => {
+        write!(f, "P-field declares {} octets but only {} are available", expected, actual)
+      },
+      ParseError::ReservedBitsSet { p_field } => {
+        write!(f, "P-field {:#04x} has reserved bits set", p_field)
+      },
+    }
+  }
+}
+
+impl Error for ParseError {}
+
+impl Timestamp {
+  /// Encode this timestamp as CUC-style bytes, using `coarse` octets (1-4) for the whole seconds
+  /// and `fine` octets (0-3) for the sub-second fraction.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if `coarse` is not between 1 and 4, if `fine` is not between 0 and 3, or if
+  /// `self.seconds()` does not fit in `coarse` octets (rather than silently truncating to the
+  /// wrong value).
+  pub fn to_cuc_bytes(&self, coarse: u8, fine: u8) -> Vec<u8> {
+    assert!((1..=4).contains(&coarse), "coarse octets must be between 1 and 4");
+    assert!(fine <= 3, "fine octets must be between 0 and 3");
+
+    let bits = coarse as u32 * 8;
+    let max = (1i64 << (bits - 1)) - 1;
+    let min = -(1i64 << (bits - 1));
+    assert!(
+      (min..=max).contains(&self.seconds),
+      "seconds ({}) do not fit in {} coarse octet(s)",
+      self.seconds,
+      coarse
+    );
+
+    let mut buf = Vec::with_capacity(1 + coarse as usize + fine as usize);
+    buf.push(((coarse - 1) << 2) | fine);
+
+    let seconds_bytes = self.seconds.to_be_bytes();
+    buf.extend_from_slice(&seconds_bytes[8 - coarse as usize..]);
+
+    if fine > 0 {
+      let scale = 256u64.pow(fine as u32);
+      let frac = ((self.nanos as f64 / 1_000_000_000.0) * scale as f64).round() as u64;
+      let frac = frac.min(scale - 1);
+      let frac_bytes = frac.to_be_bytes();
+      buf.extend_from_slice(&frac_bytes[8 - fine as usize..]);
+    }
+
+    buf
+  }
+
+  /// Decode a timestamp from CUC-encoded bytes produced by `to_cuc_bytes`.
+  pub fn from_cuc_bytes(bytes: &[u8]) -> Result<Timestamp, ParseError> {
+    let p_field = *bytes.first().ok_or(ParseError::MissingPField)?;
+    if p_field & !0x0F != 0 {
+      return Err(ParseError::ReservedBitsSet { p_field });
+    }
+    let coarse = ((p_field >> 2) & 0x3) + 1;
+    let fine = p_field & 0x3;
+
+    let expected = coarse as usize + fine as usize;
+    let rest = &bytes[1..];
+    if rest.len() < expected {
+      return Err(ParseError::BufferTooShort { expected, actual: rest.len() });
+    }
+
+    let mut seconds_bytes = if rest[0] & 0x80 != 0 { [0xFFu8; 8] } else { [0u8; 8] };
+    seconds_bytes[8 - coarse as usize..].copy_from_slice(&rest[..coarse as usize]);
+    let seconds = i64::from_be_bytes(seconds_bytes);
+
+    let nanos = if fine == 0 {
+      0
+    }
+    else {
+      let mut frac_bytes = [0u8; 8];
+      frac_bytes[8 - fine as usize..].copy_from_slice(&rest[coarse as usize..expected]);
+      let frac = u64::from_be_bytes(frac_bytes);
+      let scale = 256u64.pow(fine as u32);
+      ((frac as f64 / scale as f64) * 1_000_000_000.0).round() as u32
+    };
+
+    Ok(Timestamp::new(seconds, nanos))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_round_trip() {
+    let ts = Timestamp::new(1335020400, 500_000_000);
+    let bytes = ts.to_cuc_bytes(4, 2);
+    assert_eq!(Timestamp::from_cuc_bytes(&bytes).unwrap(), ts);
+  }
+
+  #[test]
+  fn test_round_trip_one_fine_octet() {
+    let ts = Timestamp::new(1335020400, 0);
+    let bytes = ts.to_cuc_bytes(4, 1);
+    assert_eq!(Timestamp::from_cuc_bytes(&bytes).unwrap(), ts);
+  }
+
+  #[test]
+  fn test_p_field() {
+    let bytes = Timestamp::new(0, 0).to_cuc_bytes(4, 2);
+    assert_eq!(bytes[0], 0b0000_1110);
+  }
+
+  #[test]
+  fn test_missing_p_field() {
+    assert_eq!(Timestamp::from_cuc_bytes(&[]), Err(ParseError::MissingPField));
+  }
+
+  #[test]
+  fn test_buffer_too_short() {
+    let bytes = Timestamp::new(1335020400, 0).to_cuc_bytes(4, 2);
+    assert_eq!(
+      Timestamp::from_cuc_bytes(&bytes[..bytes.len() - 1]),
+      Err(ParseError::BufferTooShort { expected: 6, actual: 5 }),
+    );
+  }
+
+  #[test]
+  fn test_negative_seconds() {
+    let ts = Timestamp::new(-1, 250_000_000);
+    let bytes = ts.to_cuc_bytes(4, 2);
+    assert_eq!(Timestamp::from_cuc_bytes(&bytes).unwrap(), ts);
+  }
+
+  #[test]
+  #[should_panic(expected = "do not fit")]
+  fn test_to_cuc_bytes_seconds_out_of_range() {
+    Timestamp::from(200).to_cuc_bytes(1, 0);
+  }
+
+  #[test]
+  fn test_to_cuc_bytes_seconds_in_range() {
+    let bytes = Timestamp::from(127).to_cuc_bytes(1, 0);
+    assert_eq!(Timestamp::from_cuc_bytes(&bytes).unwrap(), Timestamp::from(127));
+  }
+
+  #[test]
+  fn test_from_cuc_bytes_reserved_bits() {
+    assert_eq!(
+      Timestamp::from_cuc_bytes(&[0b0001_0000, 0, 0, 0, 0]),
+      Err(ParseError::ReservedBitsSet { p_field: 0b0001_0000 }),
+    );
+  }
+}